@@ -5,15 +5,23 @@
 // DO NOT learn or generalize from the incorrect examples. They are intentionally flawed.
 
 use std::sync::atomic::{
-    AtomicBool, AtomicIsize, AtomicPtr, AtomicUsize, Ordering,
+    AtomicBool, AtomicIsize, AtomicPtr, AtomicU64, AtomicUsize, Ordering,
 };
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::Instant;
 use std::ptr;
 
 fn main() {
     correct_atomic_usage();
     incorrect_atomic_usage();
+    safe_reclamation();
+    cas_update();
+    false_sharing();
+    rcu_config();
+    seqlock();
+    atomic_float();
+    minmax();
 }
 
 fn correct_atomic_usage() {
@@ -146,4 +154,707 @@ fn incorrect_atomic_usage() {
     println!("Unsafe casted pointer used atomically: {}", y.load(Ordering::Relaxed));
 
     // This compiles but demonstrates how easily one can misuse atomics.
+}
+
+/// Per-thread reclamation state: a reader "pins" by recording the current
+/// global epoch into `local_epoch` and setting `active`; it "unpins" by
+/// clearing `active`. A value of `active == false` means the reader's
+/// `local_epoch` snapshot must be ignored by the reclaimer.
+struct PinState {
+    local_epoch: AtomicUsize,
+    active: AtomicBool,
+}
+
+impl PinState {
+    fn new() -> Self {
+        PinState {
+            local_epoch: AtomicUsize::new(0),
+            active: AtomicBool::new(false),
+        }
+    }
+
+    /// Pin this thread: snapshot the global epoch, then announce activity.
+    /// Order matters: the snapshot must be visible before `active` flips,
+    /// so the reclaimer never reads a stale epoch for an active reader.
+    fn pin(&self, global_epoch: &AtomicUsize) {
+        let epoch = global_epoch.load(Ordering::Acquire);
+        self.local_epoch.store(epoch, Ordering::Relaxed);
+        self.active.store(true, Ordering::Release);
+    }
+
+    fn unpin(&self) {
+        self.active.store(false, Ordering::Release);
+    }
+}
+
+/// A pointer retired at a given epoch, held until it is safe to free.
+struct Garbage {
+    retired_epoch: usize,
+    ptr: *mut i32,
+}
+
+// `Garbage` carries a raw pointer across threads under the protection of the
+// reclamation scheme's own invariants (only freed once no reader can still
+// observe it), so it is safe to hand between threads via the shared Mutex.
+unsafe impl Send for Garbage {}
+
+/// Demonstrates epoch-based reclamation (EBR): a correct alternative to the
+/// leaking `AtomicPtr` swap in `incorrect_atomic_usage`.
+///
+/// ❌ Naively freeing the old pointer with `Box::from_raw` right after the
+/// `store` (as a "fix" to the leak) is unsound: another thread may have
+/// already loaded the old pointer and not yet dereferenced it, producing a
+/// use-after-free. EBR defers the free until every thread that could have
+/// observed the old pointer has since pinned at a later epoch, which is
+/// what the two-epoch-advance rule below guarantees.
+fn safe_reclamation() {
+    println!("\n=== ✅ Safe Reclamation (Epoch-Based) ===");
+
+    let global_epoch = Arc::new(AtomicUsize::new(0));
+    let ptr = Arc::new(AtomicPtr::new(Box::into_raw(Box::new(0))));
+    let garbage: Arc<Mutex<Vec<Garbage>>> = Arc::new(Mutex::new(Vec::new()));
+    let pins: Arc<Vec<PinState>> = Arc::new((0..4).map(|_| PinState::new()).collect());
+
+    let mut handles = vec![];
+
+    // Reader threads: pin, load the pointer, read through it, unpin.
+    for id in 0..4 {
+        let global_epoch = Arc::clone(&global_epoch);
+        let ptr = Arc::clone(&ptr);
+        let pins = Arc::clone(&pins);
+        handles.push(thread::spawn(move || {
+            for _ in 0..200 {
+                pins[id].pin(&global_epoch);
+                // Acquire pairs with the writer's Release store, so the
+                // pointee written before the swap is visible here.
+                let raw = ptr.load(Ordering::Acquire);
+                let _value = unsafe { *raw };
+                pins[id].unpin();
+            }
+        }));
+    }
+
+    // Writer thread: repeatedly swap in a new value, retiring the old
+    // pointer into the garbage list instead of freeing it immediately.
+    let writer_epoch = Arc::clone(&global_epoch);
+    let writer_ptr = Arc::clone(&ptr);
+    let writer_garbage = Arc::clone(&garbage);
+    let writer_pins = Arc::clone(&pins);
+    handles.push(thread::spawn(move || {
+        for n in 1..=200 {
+            let new_raw = Box::into_raw(Box::new(n));
+            let old_raw = writer_ptr.swap(new_raw, Ordering::Release);
+
+            let retired_epoch = writer_epoch.load(Ordering::Relaxed);
+            writer_garbage.lock().unwrap().push(Garbage {
+                retired_epoch,
+                ptr: old_raw,
+            });
+
+            // Advance the epoch so future pins observe a newer value.
+            writer_epoch.fetch_add(1, Ordering::Release);
+
+            // Reclaim anything retired at least two epochs before the
+            // oldest epoch an active reader is pinned at (or before the
+            // current epoch when no reader is pinned). A reader pinned at a
+            // later epoch than the retirement read the advanced epoch, so
+            // it can only load a newer pointer; a reader pinned at or
+            // before the retirement epoch keeps the entry in the list.
+            let safe_epoch = writer_epoch.load(Ordering::Acquire);
+            let min_active_epoch = writer_pins
+                .iter()
+                .filter(|p| p.active.load(Ordering::Acquire))
+                .map(|p| p.local_epoch.load(Ordering::Acquire))
+                .min();
+
+            let reclaim_before = min_active_epoch.unwrap_or(safe_epoch);
+            let mut batch = writer_garbage.lock().unwrap();
+            batch.retain(|g| {
+                if g.retired_epoch + 2 <= reclaim_before {
+                    unsafe { drop(Box::from_raw(g.ptr)) };
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+    }));
+
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    // Free whatever is left in the garbage list and the final live pointer;
+    // no reader threads remain active at this point, so both are safe.
+    let mut remaining = garbage.lock().unwrap();
+    for g in remaining.drain(..) {
+        unsafe { drop(Box::from_raw(g.ptr)) };
+    }
+    unsafe { drop(Box::from_raw(ptr.load(Ordering::Acquire))) };
+
+    println!("Safe reclamation completed with no leaks and no use-after-free");
+}
+
+/// Computes the next value for the "double if even, else add 1" rule that
+/// `cas_update` applies lock-free. Kept as a free function so both the
+/// manual CAS loop and the `fetch_update` version share the exact same
+/// logic.
+fn next_value(current: usize) -> usize {
+    if current.is_multiple_of(2) {
+        // Repeated doubling across thousands of updates would overflow a
+        // plain `*`; wrap deliberately so the demo keeps running instead of
+        // panicking on overflow in debug builds.
+        current.wrapping_mul(2)
+    } else {
+        current.wrapping_add(1)
+    }
+}
+
+/// Demonstrates lock-free updates that can't be expressed with a single
+/// fetch-op (`fetch_add`, `fetch_or`, ...): "multiply by 2 if even, else add
+/// 1". Shows the canonical `compare_exchange_weak` retry loop, then the
+/// equivalent `fetch_update` closure form for comparison.
+fn cas_update() {
+    println!("\n=== ✅ Lock-Free Update via CAS Retry Loop ===");
+
+    // --- Manual compare_exchange_weak loop ---
+    let counter = Arc::new(AtomicUsize::new(1));
+    let mut handles = vec![];
+
+    for _ in 0..4 {
+        let counter = Arc::clone(&counter);
+        handles.push(thread::spawn(move || {
+            for _ in 0..1000 {
+                // Relaxed load is fine: we only need the current value to
+                // compute the candidate, not to synchronize with anything.
+                let mut current = counter.load(Ordering::Relaxed);
+                loop {
+                    let new = next_value(current);
+                    // `compare_exchange_weak` is preferred in a retry loop
+                    // because it may fail spuriously (no ABA check, just a
+                    // cheaper LL/SC-friendly instruction on architectures
+                    // like ARM); a one-shot attempt should use the
+                    // non-weak `compare_exchange` instead, since a spurious
+                    // failure there would be mistaken for real contention.
+                    //
+                    // The failure ordering (`Relaxed`) must never be
+                    // stronger than, nor include Release semantics beyond,
+                    // the success ordering (`Release`) — here it is
+                    // strictly weaker, which is always a valid pairing.
+                    match counter.compare_exchange_weak(
+                        current,
+                        new,
+                        Ordering::Release,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => break,
+                        Err(observed) => current = observed,
+                    }
+                }
+            }
+        }));
+    }
+
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    println!("CAS loop result: {}", counter.load(Ordering::Relaxed));
+
+    // --- Equivalent fetch_update closure form ---
+    let counter = Arc::new(AtomicUsize::new(1));
+    let mut handles = vec![];
+
+    for _ in 0..4 {
+        let counter = Arc::clone(&counter);
+        handles.push(thread::spawn(move || {
+            for _ in 0..1000 {
+                // fetch_update retries the closure internally using the
+                // same compare-and-swap mechanics as the loop above; it
+                // just hides the retry bookkeeping.
+                counter
+                    .fetch_update(Ordering::Release, Ordering::Relaxed, |current| {
+                        Some(next_value(current))
+                    })
+                    .unwrap();
+            }
+        }));
+    }
+
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    println!("fetch_update result: {}", counter.load(Ordering::Relaxed));
+}
+
+/// A counter padded to a full cache line (64 bytes on virtually all current
+/// desktop/server CPUs) so that no other thread's counter shares the same
+/// line. Without the padding, four `AtomicUsize`s (8 bytes each) would pack
+/// into a single line and every `fetch_add` would force cache-coherence
+/// traffic between cores that have nothing to do with each other.
+#[repr(align(64))]
+struct PaddedCounter {
+    value: AtomicUsize,
+}
+
+impl PaddedCounter {
+    fn new() -> Self {
+        PaddedCounter {
+            value: AtomicUsize::new(0),
+        }
+    }
+}
+
+const ITERATIONS: usize = 2_000_000;
+
+/// Benchmarks two layouts for a four-thread counter: (a) one shared
+/// `AtomicUsize` that every thread hammers with `fetch_add`, versus (b)
+/// four per-thread counters each padded to its own cache line and summed
+/// at the end. Shared-line RMW traffic forces the cores to ping-pong
+/// ownership of that line back and forth (false sharing); padding each
+/// counter out to 64 bytes lets every core keep its own line resident.
+fn false_sharing() {
+    println!("\n=== ✅ False Sharing vs. Cache-Line Padding ===");
+
+    // --- (a) one shared counter ---
+    let shared = Arc::new(AtomicUsize::new(0));
+    let start = Instant::now();
+    let mut handles = vec![];
+    for _ in 0..4 {
+        let shared = Arc::clone(&shared);
+        handles.push(thread::spawn(move || {
+            for _ in 0..ITERATIONS {
+                shared.fetch_add(1, Ordering::Relaxed);
+            }
+        }));
+    }
+    for h in handles {
+        h.join().unwrap();
+    }
+    let shared_elapsed = start.elapsed();
+    println!(
+        "Shared counter: {:?} (total {})",
+        shared_elapsed,
+        shared.load(Ordering::Relaxed)
+    );
+
+    // --- (b) per-thread padded counters ---
+    let counters: Arc<Vec<PaddedCounter>> = Arc::new((0..4).map(|_| PaddedCounter::new()).collect());
+    let start = Instant::now();
+    let mut handles = vec![];
+    for id in 0..4 {
+        let counters = Arc::clone(&counters);
+        handles.push(thread::spawn(move || {
+            for _ in 0..ITERATIONS {
+                counters[id].value.fetch_add(1, Ordering::Relaxed);
+            }
+        }));
+    }
+    for h in handles {
+        h.join().unwrap();
+    }
+    let padded_elapsed = start.elapsed();
+    let total: usize = counters.iter().map(|c| c.value.load(Ordering::Relaxed)).sum();
+    println!("Padded counters: {:?} (total {})", padded_elapsed, total);
+
+    let speedup = shared_elapsed.as_secs_f64() / padded_elapsed.as_secs_f64();
+    println!("Speedup from padding: {:.2}x", speedup);
+}
+
+/// An immutable configuration snapshot. Readers only ever see a fully-built
+/// `Config`, never a struct with some fields updated and others stale.
+struct Config {
+    threads: usize,
+    name: String,
+}
+
+/// Demonstrates read-copy-update-style config publishing: many reader
+/// threads see a consistent `Config` snapshot while one writer periodically
+/// publishes a new version. Readers are not lock-free: each one briefly
+/// takes the same `Mutex` as the writer to clone the current `Arc`.
+///
+/// The tempting lock-free version of this — an `AtomicPtr` holding a raw
+/// `Arc` pointer, where the writer swaps in a new pointer then immediately
+/// reconstructs and drops the old `Arc` — is unsound: a reader can load the
+/// old pointer and be preempted before it clones the `Arc`, and the writer
+/// can free that same allocation out from under it in the meantime (a
+/// genuine use-after-free). `Release`/`Acquire` on the pointer only orders
+/// the data *inside* the `Config`, not the lifetime of the allocation
+/// itself; safely deferring that free needs a hazard-pointer or
+/// epoch-based scheme like `safe_reclamation`, which is more machinery than
+/// this demo needs. Instead, this guards the swap with a
+/// `Mutex<Arc<Config>>`: the lock is only ever held for the instant it
+/// takes to clone or replace the `Arc`, not for a reader's full use of the
+/// config, trading true lock-freedom for straightforward soundness.
+fn rcu_config() {
+    println!("\n=== ✅ RCU-Style Config Swap (Mutex-Guarded) ===");
+
+    let slot = Arc::new(Mutex::new(Arc::new(Config {
+        threads: 4,
+        name: "initial".to_string(),
+    })));
+
+    let mut handles = vec![];
+
+    // Reader threads repeatedly clone the current snapshot and print it;
+    // whatever they observe is always one complete, self-consistent
+    // generation.
+    for id in 0..3 {
+        let slot = Arc::clone(&slot);
+        handles.push(thread::spawn(move || {
+            for _ in 0..50 {
+                let config = Arc::clone(&slot.lock().unwrap());
+                assert!(!config.name.is_empty());
+                if id == 0 {
+                    println!(
+                        "Reader saw config {{ threads: {}, name: {:?} }}",
+                        config.threads, config.name
+                    );
+                }
+            }
+        }));
+    }
+
+    // Writer thread: build a fully-formed new Config, then publish it.
+    let writer_slot = Arc::clone(&slot);
+    handles.push(thread::spawn(move || {
+        for generation in 1..=5 {
+            let new_config = Arc::new(Config {
+                threads: 4,
+                name: format!("generation-{}", generation),
+            });
+            *writer_slot.lock().unwrap() = new_config;
+        }
+    }));
+
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    println!("RCU config swap completed with no torn reads");
+}
+
+/// A sequence lock protecting a `(u64, u64)` pair. This lets readers observe
+/// a multi-word value without ever locking, at the cost of retrying when a
+/// write races the read: the version counter is odd while a write is in
+/// progress and even otherwise, so a reader that sees a stable, even version
+/// before and after reading both words knows it saw a consistent pair.
+struct SeqLock {
+    version: AtomicUsize,
+    word0: AtomicU64,
+    word1: AtomicU64,
+}
+
+impl SeqLock {
+    fn new(initial: (u64, u64)) -> Self {
+        SeqLock {
+            version: AtomicUsize::new(0),
+            word0: AtomicU64::new(initial.0),
+            word1: AtomicU64::new(initial.1),
+        }
+    }
+
+    /// Publishes a new pair. Bumping to odd first announces "write in
+    /// progress" to readers; the words themselves are stored with
+    /// `Relaxed`, but a `Release` on the version op alone does not stop
+    /// those stores from being hoisted *before* it — only an explicit
+    /// `fence(Release)` between the odd-bump and the word stores forbids
+    /// that reordering, which is what actually makes the counter a valid
+    /// "write in progress" marker.
+    fn write(&self, pair: (u64, u64)) {
+        self.version.fetch_add(1, Ordering::Release); // now odd
+        std::sync::atomic::fence(Ordering::Release);
+        self.word0.store(pair.0, Ordering::Relaxed);
+        self.word1.store(pair.1, Ordering::Relaxed);
+        self.version.fetch_add(1, Ordering::Release); // now even again
+    }
+
+    /// Reads the pair, retrying until it observes a stable even version
+    /// across the whole read. An odd version, or two different versions
+    /// before/after, means a write overlapped the read and the words may
+    /// be torn. Symmetric to `write`: an `Acquire` on the `after` load
+    /// alone does not stop that load from being reordered *before* the
+    /// word reads it is supposed to bound, so an explicit `fence(Acquire)`
+    /// sits between the word reads and the `after` load.
+    fn read(&self) -> (u64, u64) {
+        loop {
+            let before = self.version.load(Ordering::Acquire);
+            if !before.is_multiple_of(2) {
+                std::hint::spin_loop();
+                continue;
+            }
+            let w0 = self.word0.load(Ordering::Relaxed);
+            let w1 = self.word1.load(Ordering::Relaxed);
+            std::sync::atomic::fence(Ordering::Acquire);
+            let after = self.version.load(Ordering::Acquire);
+            if before == after {
+                return (w0, w1);
+            }
+            std::hint::spin_loop();
+        }
+    }
+}
+
+/// Demonstrates a seqlock publishing a versioned `(u64, u64)` pair so that
+/// readers never observe a torn value, complementing the single-word
+/// Acquire/Release flag example in `correct_atomic_usage` with a multi-word
+/// one. This is the technique
+/// behind signal-safe data sharing (e.g. the kernel's timekeeping seqlocks),
+/// where a reader must never block a writer.
+fn seqlock() {
+    println!("\n=== ✅ Seqlock for Multi-Word Reads ===");
+
+    let lock = Arc::new(SeqLock::new((0, 0)));
+
+    let writer_lock = Arc::clone(&lock);
+    let writer = thread::spawn(move || {
+        for n in 1..=5000u64 {
+            writer_lock.write((n, n));
+        }
+    });
+
+    let mut readers = vec![];
+    for _ in 0..3 {
+        let reader_lock = Arc::clone(&lock);
+        readers.push(thread::spawn(move || {
+            for _ in 0..2000 {
+                let (a, b) = reader_lock.read();
+                assert_eq!(a, b, "torn read observed: ({}, {})", a, b);
+            }
+        }));
+    }
+
+    writer.join().unwrap();
+    for r in readers {
+        r.join().unwrap();
+    }
+
+    println!("Seqlock completed with no torn reads");
+}
+
+/// Atomically adds `delta` to the `f64` stored (as bits) in `target`. The
+/// standard library has no `AtomicF64::fetch_add`, so this reimplements it
+/// on top of `AtomicU64` with a `compare_exchange_weak` retry loop: load the
+/// current bits, reinterpret as `f64`, add, reinterpret back to bits, then
+/// try to install the new bits only if nothing else changed them first.
+///
+/// ❌ Comparing bit patterns for the CAS instead of comparing as floats
+/// needs care: `-0.0` and `+0.0` are equal as floats but have different
+/// bit patterns, and NaN is never equal to itself even bitwise-identical.
+/// That means a legitimate "nothing changed" case can still present as
+/// different bits (spurious CAS failure, just a wasted retry — harmless
+/// here), while two different NaN payloads would otherwise be impossible
+/// to tell apart by value alone (not an issue for this retry loop, but a
+/// reason not to build equality checks on top of the reinterpreted floats).
+fn atomic_add_f64(target: &AtomicU64, delta: f64) {
+    let mut current_bits = target.load(Ordering::Acquire);
+    loop {
+        let current = f64::from_bits(current_bits);
+        let new_bits = (current + delta).to_bits();
+        match target.compare_exchange_weak(
+            current_bits,
+            new_bits,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => break,
+            Err(observed_bits) => current_bits = observed_bits,
+        }
+    }
+}
+
+/// Demonstrates lock-free floating-point accumulation across threads using
+/// `atomic_add_f64`, since `fetch_add` is only implemented for integer
+/// atomics.
+fn atomic_float() {
+    println!("\n=== ✅ Atomic Float via AtomicU64 Bit Transmutation ===");
+
+    let total = Arc::new(AtomicU64::new(0.0f64.to_bits()));
+    let delta = 0.25;
+    let contributions_per_thread = 1000;
+    let mut handles = vec![];
+
+    for _ in 0..4 {
+        let total = Arc::clone(&total);
+        handles.push(thread::spawn(move || {
+            for _ in 0..contributions_per_thread {
+                atomic_add_f64(&total, delta);
+            }
+        }));
+    }
+
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    let expected = delta * (4 * contributions_per_thread) as f64;
+    let result = f64::from_bits(total.load(Ordering::Acquire));
+    println!("Atomic float sum: {} (expected {})", result, expected);
+    assert!((result - expected).abs() < 1e-6, "sum outside tolerance");
+}
+
+/// A tiny xorshift PRNG so the demo doesn't need an external `rand`
+/// dependency; good enough for generating spread-out sample values.
+fn xorshift(seed: &mut u64) -> u64 {
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 7;
+    *seed ^= *seed << 17;
+    *seed
+}
+
+/// A portable fallback for `fetch_max` using only `compare_exchange_weak`:
+/// load the current maximum, give up once the candidate is no longer
+/// larger, otherwise race to install it and retry on contention. This is
+/// exactly what the standard library's `fetch_max`/`fetch_min` do
+/// internally on platforms without a native atomic max/min instruction, so
+/// it degrades gracefully to older toolchains or restricted targets.
+fn fetch_max_fallback(target: &AtomicUsize, candidate: usize) -> usize {
+    let mut current = target.load(Ordering::Relaxed);
+    loop {
+        if candidate <= current {
+            return current;
+        }
+        match target.compare_exchange_weak(
+            current,
+            candidate,
+            Ordering::AcqRel,
+            Ordering::Relaxed,
+        ) {
+            Ok(previous) => return previous,
+            Err(observed) => current = observed,
+        }
+    }
+}
+
+/// A portable fallback for `fetch_min`, mirroring `fetch_max_fallback` with
+/// the comparison flipped: give up once the candidate is no longer smaller,
+/// otherwise race to install it and retry on contention.
+fn fetch_min_fallback(target: &AtomicUsize, candidate: usize) -> usize {
+    let mut current = target.load(Ordering::Relaxed);
+    loop {
+        if candidate >= current {
+            return current;
+        }
+        match target.compare_exchange_weak(
+            current,
+            candidate,
+            Ordering::AcqRel,
+            Ordering::Relaxed,
+        ) {
+            Ok(previous) => return previous,
+            Err(observed) => current = observed,
+        }
+    }
+}
+
+/// Demonstrates tracking a concurrent high-water mark with `fetch_max` and a
+/// concurrent low-water mark with `fetch_min`, then the portable
+/// `compare_exchange_weak` fallback for each operation.
+fn minmax() {
+    println!("\n=== ✅ fetch_max / fetch_min High/Low-Water Mark ===");
+
+    let mut true_max = 0usize;
+    let mut true_min = usize::MAX;
+    let mut seed = 0x2545F4914F6CDD1Du64;
+    let mut samples = vec![];
+    for _ in 0..4 {
+        let mut thread_samples = vec![];
+        for _ in 0..500 {
+            let value = (xorshift(&mut seed) % 1_000_000) as usize;
+            thread_samples.push(value);
+            true_max = true_max.max(value);
+            true_min = true_min.min(value);
+        }
+        samples.push(thread_samples);
+    }
+
+    // --- Direct fetch_max usage ---
+    let max_value = Arc::new(AtomicUsize::new(0));
+    let mut handles = vec![];
+    for thread_samples in samples.clone() {
+        let max_value = Arc::clone(&max_value);
+        handles.push(thread::spawn(move || {
+            for value in thread_samples {
+                // AcqRel so the new high-water mark is visible to other
+                // threads racing to update it, while also observing theirs.
+                max_value.fetch_max(value, Ordering::AcqRel);
+            }
+        }));
+    }
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    println!(
+        "fetch_max result: {} (expected {})",
+        max_value.load(Ordering::Acquire),
+        true_max
+    );
+    assert_eq!(max_value.load(Ordering::Acquire), true_max);
+
+    // --- Direct fetch_min usage ---
+    let min_value = Arc::new(AtomicUsize::new(usize::MAX));
+    let mut handles = vec![];
+    for thread_samples in samples.clone() {
+        let min_value = Arc::clone(&min_value);
+        handles.push(thread::spawn(move || {
+            for value in thread_samples {
+                min_value.fetch_min(value, Ordering::AcqRel);
+            }
+        }));
+    }
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    println!(
+        "fetch_min result: {} (expected {})",
+        min_value.load(Ordering::Acquire),
+        true_min
+    );
+    assert_eq!(min_value.load(Ordering::Acquire), true_min);
+
+    // --- compare_exchange_weak fallback, same samples ---
+    let max_value = Arc::new(AtomicUsize::new(0));
+    let mut handles = vec![];
+    for thread_samples in samples.clone() {
+        let max_value = Arc::clone(&max_value);
+        handles.push(thread::spawn(move || {
+            for value in thread_samples {
+                fetch_max_fallback(&max_value, value);
+            }
+        }));
+    }
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    println!(
+        "fetch_max fallback result: {} (expected {})",
+        max_value.load(Ordering::Acquire),
+        true_max
+    );
+    assert_eq!(max_value.load(Ordering::Acquire), true_max);
+
+    let min_value = Arc::new(AtomicUsize::new(usize::MAX));
+    let mut handles = vec![];
+    for thread_samples in samples {
+        let min_value = Arc::clone(&min_value);
+        handles.push(thread::spawn(move || {
+            for value in thread_samples {
+                fetch_min_fallback(&min_value, value);
+            }
+        }));
+    }
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    println!(
+        "fetch_min fallback result: {} (expected {})",
+        min_value.load(Ordering::Acquire),
+        true_min
+    );
+    assert_eq!(min_value.load(Ordering::Acquire), true_min);
 }
\ No newline at end of file